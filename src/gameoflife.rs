@@ -1,9 +1,9 @@
 use clap::Parser;
-use color_eyre::{config::HookBuilder, eyre, Result};
+use color_eyre::{config::HookBuilder, eyre, eyre::Context, Result};
 use crossterm::{
     event,
     event::KeyCode,
-    event::{Event, KeyEventKind},
+    event::{DisableMouseCapture, EnableMouseCapture, Event, KeyEventKind, MouseButton, MouseEventKind},
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
     ExecutableCommand,
 };
@@ -17,8 +17,10 @@ use ratatui::{
     widgets::Widget,
 };
 use std::{
+    collections::{HashMap, VecDeque},
     io::stdout,
     panic,
+    path::{Path, PathBuf},
     time::{Duration, Instant},
 };
 
@@ -27,6 +29,69 @@ use std::{
 struct Args {
     #[arg(short, long, default_value_t = 0.0)]
     fps: f64,
+
+    /// Starting pattern to load instead of a random grid, as an RLE (`.rle`)
+    /// or plaintext (`.cells`) file.
+    #[arg(short, long)]
+    pattern: Option<PathBuf>,
+
+    /// Life-like rulestring, e.g. `B3/S23` (Conway's Life) or `B36/S23` (HighLife).
+    #[arg(short = 'R', long, default_value = "B3/S23", value_parser = Rule::parse_arg)]
+    rule: Rule,
+
+    /// Re-seed the grid with random live cells every this many generations,
+    /// to keep long unattended runs from stabilizing into static blocks.
+    #[arg(long)]
+    seed_interval: Option<u32>,
+
+    /// Percentage of cells to flip alive on each re-seed (see `--seed-interval`).
+    #[arg(long, default_value_t = 5)]
+    seed_density: u8,
+}
+
+/// A Life-like rulestring such as `B3/S23`: `birth[n]`/`survive[n]` say
+/// whether a dead/live cell with `n` live neighbors becomes/stays alive.
+#[derive(Debug, Clone, Copy)]
+struct Rule {
+    birth: [bool; 9],
+    survive: [bool; 9],
+}
+
+impl Rule {
+    fn parse(s: &str) -> Result<Self> {
+        let (birth_part, survive_part) = s
+            .split_once('/')
+            .ok_or_else(|| eyre::eyre!("rule {s:?} must be of the form B.../S..."))?;
+        let birth_digits = birth_part
+            .strip_prefix('B')
+            .ok_or_else(|| eyre::eyre!("rule {s:?} must start with a B... part"))?;
+        let survive_digits = survive_part
+            .strip_prefix('S')
+            .ok_or_else(|| eyre::eyre!("rule {s:?} must have an S... part"))?;
+
+        let mut birth = [false; 9];
+        for c in birth_digits.chars() {
+            let n = c
+                .to_digit(10)
+                .filter(|&n| n <= 8)
+                .ok_or_else(|| eyre::eyre!("rule {s:?} has an invalid birth count {c:?}"))?;
+            birth[n as usize] = true;
+        }
+        let mut survive = [false; 9];
+        for c in survive_digits.chars() {
+            let n = c
+                .to_digit(10)
+                .filter(|&n| n <= 8)
+                .ok_or_else(|| eyre::eyre!("rule {s:?} has an invalid survive count {c:?}"))?;
+            survive[n as usize] = true;
+        }
+
+        Ok(Self { birth, survive })
+    }
+
+    fn parse_arg(s: &str) -> std::result::Result<Self, String> {
+        Self::parse(s).map_err(|e| e.to_string())
+    }
 }
 
 #[derive(Debug)]
@@ -51,53 +116,103 @@ struct FpsWidget {
     fps: Option<f32>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
-enum GridCell {
-    Dead = 0,
-    Alive = 1,
+/// How many generations a just-died cell takes to fade from grey to black.
+const FADE_GENERATIONS: u8 = 20;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct GridCell {
+    alive: bool,
+    /// Whether this cell has ever been alive; gates the dead-cell fade so an
+    /// empty cell that was never alive renders black instead of fading from grey.
+    ever_alive: bool,
+    /// Generations this cell has held its current `alive` state, saturating.
+    age: u8,
 }
 
 impl GridCell {
+    const DEAD: Self = Self {
+        alive: false,
+        ever_alive: false,
+        age: 0,
+    };
+    const ALIVE: Self = Self {
+        alive: true,
+        ever_alive: true,
+        age: 0,
+    };
+
     fn into(&self) -> u8 {
-        match self {
-            GridCell::Dead => 0,
-            GridCell::Alive => 1,
-        }
+        u8::from(self.alive)
     }
 
+    #[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
     fn render(&self, cell: &mut Cell) {
-        match self {
-            GridCell::Alive => cell
-                .set_fg(Color::Rgb(255, 255, 255))
-                .set_bg(Color::Rgb(255, 255, 255)),
-            GridCell::Dead => cell.set_fg(Color::Rgb(0, 0, 0)).set_bg(Color::Rgb(0, 0, 0)),
+        let color = if self.alive {
+            // Bright cyan at birth, cooling toward deep blue as the cell ages.
+            let t = f32::from(self.age) / f32::from(u8::MAX);
+            Color::Rgb(0, lerp(255, 0, t), lerp(255, 180, t))
+        } else if self.ever_alive && self.age < FADE_GENERATIONS {
+            let grey = lerp(80, 0, f32::from(self.age) / f32::from(FADE_GENERATIONS));
+            Color::Rgb(grey, grey, grey)
+        } else {
+            Color::Rgb(0, 0, 0)
         };
+        cell.set_fg(color).set_bg(color);
     }
 }
 
-#[derive(Debug, Default)]
+#[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+fn lerp(from: u8, to: u8, t: f32) -> u8 {
+    (f32::from(from) + (f32::from(to) - f32::from(from)) * t).clamp(0.0, 255.0) as u8
+}
+
+/// How many past generation hashes to remember for cycle detection.
+const HISTORY_CAPACITY: usize = 256;
+
+#[derive(Debug)]
 struct GameOfLifeWidget {
     grid: Option<(Vec<GridCell>, Vec<GridCell>)>,
     diff: Option<u32>,
+    pattern: Option<Vec<Vec<bool>>>,
+    rule: Rule,
+    area: Option<Rect>,
+    paused: bool,
+    step: bool,
+    generation: u32,
+    history: HashMap<u64, u32>,
+    history_order: VecDeque<(u64, u32)>,
+    cycle: Option<String>,
+    seed_interval: Option<u32>,
+    seed_density: u8,
 }
 
 fn main() -> Result<()> {
     let args = Args::parse();
     install_error_hooks()?;
     let terminal = init_terminal()?;
-    App::new(args).run(terminal)?;
+    App::new(args)?.run(terminal)?;
     restore_terminal()?;
     Ok(())
 }
 
 impl App {
-    pub fn new(args: Args) -> Self {
-        Self {
-            args,
+    pub fn new(args: Args) -> Result<Self> {
+        let pattern = args
+            .pattern
+            .as_deref()
+            .map(load_pattern_file)
+            .transpose()?;
+        Ok(Self {
             state: AppState::default(),
             fps_widget: FpsWidget::default(),
-            game_of_life: GameOfLifeWidget::default(),
-        }
+            game_of_life: GameOfLifeWidget::new(
+                pattern,
+                args.rule,
+                args.seed_interval,
+                args.seed_density,
+            ),
+            args,
+        })
     }
 
     pub fn run(mut self, mut terminal: Terminal<impl Backend>) -> Result<()> {
@@ -119,26 +234,54 @@ impl App {
         }
         let timeout = Duration::from_secs_f64(timeout);
         if event::poll(timeout)? {
-            if let Event::Key(key) = event::read()? {
-                if key.kind == KeyEventKind::Press && key.code == KeyCode::Char('q') {
-                    self.state = AppState::Quit;
-                };
-                if key.kind == KeyEventKind::Press && key.code == KeyCode::Char('r') {
-                    self.game_of_life = GameOfLifeWidget::default();
-                };
+            match event::read()? {
+                Event::Key(key) => {
+                    if key.kind == KeyEventKind::Press && key.code == KeyCode::Char('q') {
+                        self.state = AppState::Quit;
+                    };
+                    if key.kind == KeyEventKind::Press && key.code == KeyCode::Char('r') {
+                        self.game_of_life = GameOfLifeWidget::new(
+                            self.game_of_life.pattern.clone(),
+                            self.game_of_life.rule,
+                            self.game_of_life.seed_interval,
+                            self.game_of_life.seed_density,
+                        );
+                    };
+                    if key.kind == KeyEventKind::Press && key.code == KeyCode::Char(' ') {
+                        self.game_of_life.paused = !self.game_of_life.paused;
+                    };
+                    if key.kind == KeyEventKind::Press
+                        && key.code == KeyCode::Char('n')
+                        && self.game_of_life.paused
+                    {
+                        self.game_of_life.step = true;
+                    };
 
-                if key.kind == KeyEventKind::Press
-                    && key.code == KeyCode::Char('f')
-                    && self.args.fps >= 1.0
-                {
-                    self.args.fps -= 1.0;
-                };
-                if key.kind == KeyEventKind::Press
-                    && key.code == KeyCode::Char('s')
-                    && self.args.fps < f64::MAX
-                {
-                    self.args.fps += 1.0;
-                };
+                    if key.kind == KeyEventKind::Press
+                        && key.code == KeyCode::Char('f')
+                        && self.args.fps >= 1.0
+                    {
+                        self.args.fps -= 1.0;
+                    };
+                    if key.kind == KeyEventKind::Press
+                        && key.code == KeyCode::Char('s')
+                        && self.args.fps < f64::MAX
+                    {
+                        self.args.fps += 1.0;
+                    };
+                }
+                Event::Mouse(mouse) => match mouse.kind {
+                    MouseEventKind::Down(MouseButton::Left) | MouseEventKind::Drag(MouseButton::Left) => {
+                        self.game_of_life
+                            .set_cell(mouse.column, mouse.row, GridCell::ALIVE);
+                    }
+                    MouseEventKind::Down(MouseButton::Right) | MouseEventKind::Drag(MouseButton::Right) => {
+                        self.game_of_life
+                            .set_cell(mouse.column, mouse.row, GridCell::DEAD);
+                    }
+                    _ => {}
+                },
+                _ => {}
             }
         }
         Ok(())
@@ -151,7 +294,9 @@ impl Widget for &mut App {
         let [top, area] = Layout::vertical([Length(1), Min(0)]).areas(area);
         let [title, info] = Layout::horizontal([Min(0), Constraint::Percentage(50)]).areas(top);
         let [osc, fps] = Layout::horizontal([Min(0), Constraint::Percentage(50)]).areas(info);
-        Text::from("Game of Life. Press q to quit, r to restart")
+        Text::from(
+            "Game of Life. Press q to quit, r to restart, space to pause, n to step, click to draw",
+        )
             .left_aligned()
             .render(title, buf);
         self.fps_widget.render(fps, buf);
@@ -195,6 +340,7 @@ impl FpsWidget {
 
 impl Widget for &mut GameOfLifeWidget {
     fn render(self, area: Rect, buf: &mut Buffer) {
+        self.area = Some(area);
         self.calculate_game(area);
         let Some((grid, _)) = self.grid.as_ref() else {
             return;
@@ -210,11 +356,37 @@ impl Widget for &mut GameOfLifeWidget {
 }
 
 impl GameOfLifeWidget {
+    fn new(
+        pattern: Option<Vec<Vec<bool>>>,
+        rule: Rule,
+        seed_interval: Option<u32>,
+        seed_density: u8,
+    ) -> Self {
+        Self {
+            grid: None,
+            diff: None,
+            pattern,
+            rule,
+            area: None,
+            paused: false,
+            step: false,
+            generation: 0,
+            history: HashMap::new(),
+            history_order: VecDeque::new(),
+            cycle: None,
+            seed_interval,
+            seed_density,
+        }
+    }
+
     fn print_diff(&mut self, area: Rect, buf: &mut Buffer) {
         let Some(diff) = self.diff.take() else {
             return;
         };
-        let text = format!("{diff} blocks changed");
+        let text = match self.cycle.take() {
+            Some(cycle) => format!("{diff} blocks changed ({cycle})"),
+            None => format!("{diff} blocks changed"),
+        };
         Text::from(text).left_aligned().render(area, buf);
     }
 
@@ -233,6 +405,12 @@ impl GameOfLifeWidget {
             return;
         }
 
+        if self.paused && !self.step {
+            self.diff = None;
+            return;
+        }
+        self.step = false;
+
         let Some((grid, ref mut cached)) = self.grid.as_mut() else {
             return;
         };
@@ -247,42 +425,42 @@ impl GameOfLifeWidget {
                 // Tested the indexes with the safe alternatives so the
                 // limits would be correct.
                 let up = if y == 0 {
-                    &GridCell::Dead
+                    &GridCell::DEAD
                 } else {
                     unsafe { cached.get_unchecked((y - 1) * len + x) }
                 };
                 let upleft = if y == 0 || x == 0 {
-                    &GridCell::Dead
+                    &GridCell::DEAD
                 } else {
                     unsafe { cached.get_unchecked((y - 1) * len + x - 1) }
                 };
                 let upright = if y == 0 || x == width - 1 {
-                    &GridCell::Dead
+                    &GridCell::DEAD
                 } else {
                     unsafe { cached.get_unchecked((y - 1) * len + x + 1) }
                 };
                 let down = if y == height - 1 {
-                    &GridCell::Dead
+                    &GridCell::DEAD
                 } else {
                     unsafe { cached.get_unchecked((y + 1) * len + x) }
                 };
                 let downleft = if y == height - 1 || x == 0 {
-                    &GridCell::Dead
+                    &GridCell::DEAD
                 } else {
                     unsafe { cached.get_unchecked((y + 1) * len + x - 1) }
                 };
                 let downright = if y == height - 1 || x == width - 1 {
-                    &GridCell::Dead
+                    &GridCell::DEAD
                 } else {
                     unsafe { cached.get_unchecked((y + 1) * len + x + 1) }
                 };
                 let left = if x == 0 {
-                    &GridCell::Dead
+                    &GridCell::DEAD
                 } else {
                     unsafe { cached.get_unchecked(y * len + x - 1) }
                 };
                 let right = if x == width - 1 {
-                    &GridCell::Dead
+                    &GridCell::DEAD
                 } else {
                     unsafe { cached.get_unchecked(y * len + x + 1) }
                 };
@@ -296,47 +474,221 @@ impl GameOfLifeWidget {
                     + upright.into()
                     + downleft.into()
                     + downright.into();
-                // Any live cell with fewer than two live neighbors dies, as if by underpopulation.
-                // Any live cell with two or three live neighbors lives on to the next generation.
-                // Any live cell with more than three live neighbors dies, as if by overpopulation.
-                // Any dead cell with exactly three live neighbors becomes a live cell, as if by reproduction.
-                *cell = match (neighbors, cached) {
-                    (0..2, GridCell::Dead) => {
-                        continue;
-                    }
-                    (0..2, GridCell::Alive) => GridCell::Dead,
-                    (2 | 3, GridCell::Alive) => {
-                        continue;
-                    }
-                    (4.., GridCell::Alive) => GridCell::Dead,
-                    (3, GridCell::Dead) => GridCell::Alive,
-                    (_, GridCell::Dead) => {
-                        continue;
-                    }
+                let new_alive = if cached.alive {
+                    self.rule.survive[neighbors as usize]
+                } else {
+                    self.rule.birth[neighbors as usize]
+                };
+                // Keep aging while the alive/dead state holds; reset on every flip
+                // (birth starts a cell's life, death starts its fade-out).
+                let new_age = if new_alive == cached.alive {
+                    cached.age.saturating_add(1)
+                } else {
+                    diff += 1;
+                    0
+                };
+                *cell = GridCell {
+                    alive: new_alive,
+                    ever_alive: cached.ever_alive || new_alive,
+                    age: new_age,
                 };
-                diff += 1;
             }
         }
         self.diff = Some(diff);
+
+        self.generation += 1;
+        let hash = fnv1a_hash(grid);
+        self.cycle = self.history.get(&hash).map(|&seen_at| {
+            let period = self.generation - seen_at;
+            if period == 1 {
+                "still life".to_string()
+            } else {
+                format!("period-{period} oscillator")
+            }
+        });
+        self.history.insert(hash, self.generation);
+        self.history_order.push_back((hash, self.generation));
+        if self.history_order.len() > HISTORY_CAPACITY {
+            if let Some((old_hash, old_gen)) = self.history_order.pop_front() {
+                if self.history.get(&old_hash) == Some(&old_gen) {
+                    self.history.remove(&old_hash);
+                }
+            }
+        }
+
+        if self
+            .seed_interval
+            .is_some_and(|interval| interval > 0 && self.generation.is_multiple_of(interval))
+        {
+            reseed(grid, self.seed_density);
+        }
     }
 
     fn generate_game(&mut self, size: Rect) {
         let Rect { width, height, .. } = size;
         let height = height as usize;
         let width = width as usize;
-        let mut grid = Vec::with_capacity(height * width);
-        for _ in 0..height {
-            for _ in 0..width {
+        let mut grid = vec![GridCell::DEAD; height * width];
+
+        if let Some(pattern) = &self.pattern {
+            stamp_pattern(&mut grid, width, height, pattern);
+        } else {
+            for cell in &mut grid {
                 if rand::random() {
-                    grid.push(GridCell::Alive);
-                } else {
-                    grid.push(GridCell::Dead);
+                    *cell = GridCell::ALIVE;
                 }
             }
         }
 
         self.grid.replace((grid.clone(), grid));
     }
+
+    /// Paints the grid cell under terminal position `(column, row)`, if any,
+    /// to `state`. No-op outside the widget's last rendered area.
+    fn set_cell(&mut self, column: u16, row: u16, state: GridCell) {
+        let Some(area) = self.area else {
+            return;
+        };
+        if !area.contains(ratatui::layout::Position { x: column, y: row }) {
+            return;
+        }
+        let Some((grid, _)) = self.grid.as_mut() else {
+            return;
+        };
+        let width = area.width as usize;
+        let x = (column - area.left()) as usize;
+        let y = (row - area.top()) as usize;
+        grid[y * width + x] = state;
+
+        // The edit invalidates any cycle detected against the un-edited history.
+        self.history.clear();
+        self.history_order.clear();
+    }
+}
+
+/// FNV-1a over each cell's packed alive/dead state, used to recognize a
+/// previously-seen generation for oscillator/still-life detection.
+fn fnv1a_hash(grid: &[GridCell]) -> u64 {
+    const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    let mut hash = FNV_OFFSET_BASIS;
+    for cell in grid {
+        hash ^= u64::from(cell.into());
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+    hash
+}
+
+/// Flips roughly `density_percent`% of `grid`'s cells to alive, in place.
+fn reseed(grid: &mut [GridCell], density_percent: u8) {
+    let density = f64::from(density_percent) / 100.0;
+    for cell in grid {
+        if rand::random::<f64>() < density {
+            *cell = GridCell::ALIVE;
+        }
+    }
+}
+
+/// Stamps a pattern (rows of alive/dead flags) centered into `grid`,
+/// silently clipping anything that falls outside `width`x`height`. Along an
+/// axis where the pattern is larger than the grid it is not centered: it
+/// starts at the top/left edge and only its bottom/right part is clipped.
+fn stamp_pattern(grid: &mut [GridCell], width: usize, height: usize, pattern: &[Vec<bool>]) {
+    let pattern_height = pattern.len();
+    let pattern_width = pattern.iter().map(Vec::len).max().unwrap_or(0);
+    let top = height.saturating_sub(pattern_height) / 2;
+    let left = width.saturating_sub(pattern_width) / 2;
+
+    for (y, row) in pattern.iter().enumerate() {
+        let Some(gy) = top.checked_add(y).filter(|&gy| gy < height) else {
+            continue;
+        };
+        for (x, &alive) in row.iter().enumerate() {
+            let Some(gx) = left.checked_add(x).filter(|&gx| gx < width) else {
+                continue;
+            };
+            if alive {
+                grid[gy * width + gx] = GridCell::ALIVE;
+            }
+        }
+    }
+}
+
+/// Loads a starting pattern from an RLE (`.rle`) or plaintext (`.cells`) file.
+fn load_pattern_file(path: &Path) -> Result<Vec<Vec<bool>>> {
+    let content = std::fs::read_to_string(path)
+        .wrap_err_with(|| format!("failed to read pattern file {}", path.display()))?;
+    let is_rle = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("rle"));
+    Ok(if is_rle {
+        parse_rle_pattern(&content)
+    } else {
+        parse_cells_pattern(&content)
+    })
+}
+
+/// Parses the plaintext `.cells` format: `!`-prefixed comment lines, then
+/// rows where `O`/`*` is alive and anything else (typically `.` or space) is dead.
+fn parse_cells_pattern(content: &str) -> Vec<Vec<bool>> {
+    content
+        .lines()
+        .filter(|line| !line.starts_with('!'))
+        .map(|line| line.chars().map(|c| matches!(c, 'O' | '*')).collect())
+        .collect()
+}
+
+/// Parses the RLE format: optional `#` comment lines, a header line
+/// `x = <w>, y = <h>, rule = ...`, then a token stream of optional run-count
+/// integers followed by `b` (dead), `o` (alive) or `$` (end of row), terminated by `!`.
+fn parse_rle_pattern(content: &str) -> Vec<Vec<bool>> {
+    let mut lines = content.lines().map(str::trim).filter(|line| !line.is_empty());
+    let Some(header) = lines.find(|line| !line.starts_with('#')) else {
+        return Vec::new();
+    };
+    let width = header
+        .split(',')
+        .find_map(|part| {
+            let part = part.trim();
+            part.strip_prefix("x = ").or_else(|| part.strip_prefix("x="))
+        })
+        .and_then(|value| value.trim().parse::<usize>().ok())
+        .unwrap_or(0);
+
+    let mut rows = vec![vec![false; width]];
+    let mut run = String::new();
+    let mut x = 0usize;
+    'tokens: for ch in lines.flat_map(str::chars) {
+        match ch {
+            '0'..='9' => run.push(ch),
+            'b' | 'o' | '$' | '!' => {
+                let count = std::mem::take(&mut run).parse::<usize>().unwrap_or(1);
+                match ch {
+                    'b' => x += count,
+                    'o' => {
+                        let row = rows.last_mut().expect("rows always has at least one entry");
+                        for _ in 0..count {
+                            if x < row.len() {
+                                row[x] = true;
+                            }
+                            x += 1;
+                        }
+                    }
+                    '$' => {
+                        for _ in 0..count {
+                            rows.push(vec![false; width]);
+                        }
+                        x = 0;
+                    }
+                    _ => break 'tokens,
+                }
+            }
+            _ => {}
+        }
+    }
+    rows
 }
 
 fn install_error_hooks() -> Result<()> {
@@ -356,7 +708,9 @@ fn install_error_hooks() -> Result<()> {
 
 fn init_terminal() -> Result<Terminal<impl Backend>> {
     enable_raw_mode()?;
-    stdout().execute(EnterAlternateScreen)?;
+    stdout()
+        .execute(EnterAlternateScreen)?
+        .execute(EnableMouseCapture)?;
     let mut terminal = Terminal::new(CrosstermBackend::new(stdout()))?;
     terminal.clear()?;
     terminal.hide_cursor()?;
@@ -365,6 +719,8 @@ fn init_terminal() -> Result<Terminal<impl Backend>> {
 
 fn restore_terminal() -> Result<()> {
     disable_raw_mode()?;
-    stdout().execute(LeaveAlternateScreen)?;
+    stdout()
+        .execute(DisableMouseCapture)?
+        .execute(LeaveAlternateScreen)?;
     Ok(())
 }